@@ -0,0 +1,140 @@
+//! The [`DrmFormat`] enum, listing every pixel format fourcc this crate
+//! recognizes: a subset of the canonical list in the Linux kernel's
+//! `include/uapi/drm/drm_fourcc.h`, via the raw values in
+//! [`consts`](crate::consts). Kernel formats not listed here are not
+//! recognized.
+
+use crate::consts::*;
+
+/// Every pixel format fourcc recognized by this crate. Cast to `u32` to get
+/// the raw fourcc value.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum DrmFormat {
+    Rgb332 = DRM_FOURCC_RGB332,
+    Bgr233 = DRM_FOURCC_BGR233,
+
+    Xrgb1555 = DRM_FOURCC_XRGB1555,
+    Xbgr1555 = DRM_FOURCC_XBGR1555,
+    Rgbx5551 = DRM_FOURCC_RGBX5551,
+    Bgrx5551 = DRM_FOURCC_BGRX5551,
+    Argb1555 = DRM_FOURCC_ARGB1555,
+    Abgr1555 = DRM_FOURCC_ABGR1555,
+    Rgba5551 = DRM_FOURCC_RGBA5551,
+    Bgra5551 = DRM_FOURCC_BGRA5551,
+    Rgb565 = DRM_FOURCC_RGB565,
+    Bgr565 = DRM_FOURCC_BGR565,
+
+    Rgb888 = DRM_FOURCC_RGB888,
+    Bgr888 = DRM_FOURCC_BGR888,
+
+    Xrgb8888 = DRM_FOURCC_XRGB8888,
+    Xbgr8888 = DRM_FOURCC_XBGR8888,
+    Rgbx8888 = DRM_FOURCC_RGBX8888,
+    Bgrx8888 = DRM_FOURCC_BGRX8888,
+    Argb8888 = DRM_FOURCC_ARGB8888,
+    Abgr8888 = DRM_FOURCC_ABGR8888,
+    Rgba8888 = DRM_FOURCC_RGBA8888,
+    Bgra8888 = DRM_FOURCC_BGRA8888,
+
+    Xrgb2101010 = DRM_FOURCC_XRGB2101010,
+    Xbgr2101010 = DRM_FOURCC_XBGR2101010,
+    Rgbx1010102 = DRM_FOURCC_RGBX1010102,
+    Bgrx1010102 = DRM_FOURCC_BGRX1010102,
+    Argb2101010 = DRM_FOURCC_ARGB2101010,
+    Abgr2101010 = DRM_FOURCC_ABGR2101010,
+    Rgba1010102 = DRM_FOURCC_RGBA1010102,
+    Bgra1010102 = DRM_FOURCC_BGRA1010102,
+
+    Yuyv = DRM_FOURCC_YUYV,
+    Yvyu = DRM_FOURCC_YVYU,
+    Uyvy = DRM_FOURCC_UYVY,
+    Vyuy = DRM_FOURCC_VYUY,
+    Ayuv = DRM_FOURCC_AYUV,
+
+    Nv12 = DRM_FOURCC_NV12,
+    Nv21 = DRM_FOURCC_NV21,
+    Nv16 = DRM_FOURCC_NV16,
+    Nv61 = DRM_FOURCC_NV61,
+    Nv24 = DRM_FOURCC_NV24,
+    Nv42 = DRM_FOURCC_NV42,
+
+    Yuv420 = DRM_FOURCC_YUV420,
+    Yvu420 = DRM_FOURCC_YVU420,
+    Yuv422 = DRM_FOURCC_YUV422,
+    Yvu422 = DRM_FOURCC_YVU422,
+    Yuv444 = DRM_FOURCC_YUV444,
+    Yvu444 = DRM_FOURCC_YVU444,
+}
+
+impl DrmFormat {
+    /// Construct a `DrmFormat` from its raw fourcc value. `const fn` so
+    /// formats can be looked up in const contexts, e.g. to build const
+    /// tables keyed by raw fourcc values.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFormat;
+    /// const XRGB8888: Option<DrmFormat> = DrmFormat::from_u32(875713112);
+    /// assert_eq!(XRGB8888, Some(DrmFormat::Xrgb8888));
+    /// ```
+    pub const fn from_u32(value: u32) -> Option<Self> {
+        Some(match value {
+            DRM_FOURCC_RGB332 => DrmFormat::Rgb332,
+            DRM_FOURCC_BGR233 => DrmFormat::Bgr233,
+
+            DRM_FOURCC_XRGB1555 => DrmFormat::Xrgb1555,
+            DRM_FOURCC_XBGR1555 => DrmFormat::Xbgr1555,
+            DRM_FOURCC_RGBX5551 => DrmFormat::Rgbx5551,
+            DRM_FOURCC_BGRX5551 => DrmFormat::Bgrx5551,
+            DRM_FOURCC_ARGB1555 => DrmFormat::Argb1555,
+            DRM_FOURCC_ABGR1555 => DrmFormat::Abgr1555,
+            DRM_FOURCC_RGBA5551 => DrmFormat::Rgba5551,
+            DRM_FOURCC_BGRA5551 => DrmFormat::Bgra5551,
+            DRM_FOURCC_RGB565 => DrmFormat::Rgb565,
+            DRM_FOURCC_BGR565 => DrmFormat::Bgr565,
+
+            DRM_FOURCC_RGB888 => DrmFormat::Rgb888,
+            DRM_FOURCC_BGR888 => DrmFormat::Bgr888,
+
+            DRM_FOURCC_XRGB8888 => DrmFormat::Xrgb8888,
+            DRM_FOURCC_XBGR8888 => DrmFormat::Xbgr8888,
+            DRM_FOURCC_RGBX8888 => DrmFormat::Rgbx8888,
+            DRM_FOURCC_BGRX8888 => DrmFormat::Bgrx8888,
+            DRM_FOURCC_ARGB8888 => DrmFormat::Argb8888,
+            DRM_FOURCC_ABGR8888 => DrmFormat::Abgr8888,
+            DRM_FOURCC_RGBA8888 => DrmFormat::Rgba8888,
+            DRM_FOURCC_BGRA8888 => DrmFormat::Bgra8888,
+
+            DRM_FOURCC_XRGB2101010 => DrmFormat::Xrgb2101010,
+            DRM_FOURCC_XBGR2101010 => DrmFormat::Xbgr2101010,
+            DRM_FOURCC_RGBX1010102 => DrmFormat::Rgbx1010102,
+            DRM_FOURCC_BGRX1010102 => DrmFormat::Bgrx1010102,
+            DRM_FOURCC_ARGB2101010 => DrmFormat::Argb2101010,
+            DRM_FOURCC_ABGR2101010 => DrmFormat::Abgr2101010,
+            DRM_FOURCC_RGBA1010102 => DrmFormat::Rgba1010102,
+            DRM_FOURCC_BGRA1010102 => DrmFormat::Bgra1010102,
+
+            DRM_FOURCC_YUYV => DrmFormat::Yuyv,
+            DRM_FOURCC_YVYU => DrmFormat::Yvyu,
+            DRM_FOURCC_UYVY => DrmFormat::Uyvy,
+            DRM_FOURCC_VYUY => DrmFormat::Vyuy,
+            DRM_FOURCC_AYUV => DrmFormat::Ayuv,
+
+            DRM_FOURCC_NV12 => DrmFormat::Nv12,
+            DRM_FOURCC_NV21 => DrmFormat::Nv21,
+            DRM_FOURCC_NV16 => DrmFormat::Nv16,
+            DRM_FOURCC_NV61 => DrmFormat::Nv61,
+            DRM_FOURCC_NV24 => DrmFormat::Nv24,
+            DRM_FOURCC_NV42 => DrmFormat::Nv42,
+
+            DRM_FOURCC_YUV420 => DrmFormat::Yuv420,
+            DRM_FOURCC_YVU420 => DrmFormat::Yvu420,
+            DRM_FOURCC_YUV422 => DrmFormat::Yuv422,
+            DRM_FOURCC_YVU422 => DrmFormat::Yvu422,
+            DRM_FOURCC_YUV444 => DrmFormat::Yuv444,
+            DRM_FOURCC_YVU444 => DrmFormat::Yvu444,
+
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,62 @@
+//! Raw fourcc `u32` values for every format in
+//! [`as_enum::DrmFormat`](crate::as_enum::DrmFormat), built with the same
+//! `fourcc_code` macro the kernel uses in `drm_fourcc.h`.
+
+const fn fourcc_code(a: u8, b: u8, c: u8, d: u8) -> u32 {
+    (a as u32) | ((b as u32) << 8) | ((c as u32) << 16) | ((d as u32) << 24)
+}
+
+pub const DRM_FOURCC_RGB332: u32 = fourcc_code(b'R', b'G', b'B', b'8');
+pub const DRM_FOURCC_BGR233: u32 = fourcc_code(b'B', b'G', b'R', b'8');
+
+pub const DRM_FOURCC_XRGB1555: u32 = fourcc_code(b'X', b'R', b'1', b'5');
+pub const DRM_FOURCC_XBGR1555: u32 = fourcc_code(b'X', b'B', b'1', b'5');
+pub const DRM_FOURCC_RGBX5551: u32 = fourcc_code(b'R', b'X', b'1', b'5');
+pub const DRM_FOURCC_BGRX5551: u32 = fourcc_code(b'B', b'X', b'1', b'5');
+pub const DRM_FOURCC_ARGB1555: u32 = fourcc_code(b'A', b'R', b'1', b'5');
+pub const DRM_FOURCC_ABGR1555: u32 = fourcc_code(b'A', b'B', b'1', b'5');
+pub const DRM_FOURCC_RGBA5551: u32 = fourcc_code(b'R', b'A', b'1', b'5');
+pub const DRM_FOURCC_BGRA5551: u32 = fourcc_code(b'B', b'A', b'1', b'5');
+pub const DRM_FOURCC_RGB565: u32 = fourcc_code(b'R', b'G', b'1', b'6');
+pub const DRM_FOURCC_BGR565: u32 = fourcc_code(b'B', b'G', b'1', b'6');
+
+pub const DRM_FOURCC_RGB888: u32 = fourcc_code(b'R', b'G', b'2', b'4');
+pub const DRM_FOURCC_BGR888: u32 = fourcc_code(b'B', b'G', b'2', b'4');
+
+pub const DRM_FOURCC_XRGB8888: u32 = fourcc_code(b'X', b'R', b'2', b'4');
+pub const DRM_FOURCC_XBGR8888: u32 = fourcc_code(b'X', b'B', b'2', b'4');
+pub const DRM_FOURCC_RGBX8888: u32 = fourcc_code(b'R', b'X', b'2', b'4');
+pub const DRM_FOURCC_BGRX8888: u32 = fourcc_code(b'B', b'X', b'2', b'4');
+pub const DRM_FOURCC_ARGB8888: u32 = fourcc_code(b'A', b'R', b'2', b'4');
+pub const DRM_FOURCC_ABGR8888: u32 = fourcc_code(b'A', b'B', b'2', b'4');
+pub const DRM_FOURCC_RGBA8888: u32 = fourcc_code(b'R', b'A', b'2', b'4');
+pub const DRM_FOURCC_BGRA8888: u32 = fourcc_code(b'B', b'A', b'2', b'4');
+
+pub const DRM_FOURCC_XRGB2101010: u32 = fourcc_code(b'X', b'R', b'3', b'0');
+pub const DRM_FOURCC_XBGR2101010: u32 = fourcc_code(b'X', b'B', b'3', b'0');
+pub const DRM_FOURCC_RGBX1010102: u32 = fourcc_code(b'R', b'X', b'3', b'0');
+pub const DRM_FOURCC_BGRX1010102: u32 = fourcc_code(b'B', b'X', b'3', b'0');
+pub const DRM_FOURCC_ARGB2101010: u32 = fourcc_code(b'A', b'R', b'3', b'0');
+pub const DRM_FOURCC_ABGR2101010: u32 = fourcc_code(b'A', b'B', b'3', b'0');
+pub const DRM_FOURCC_RGBA1010102: u32 = fourcc_code(b'R', b'A', b'3', b'0');
+pub const DRM_FOURCC_BGRA1010102: u32 = fourcc_code(b'B', b'A', b'3', b'0');
+
+pub const DRM_FOURCC_YUYV: u32 = fourcc_code(b'Y', b'U', b'Y', b'V');
+pub const DRM_FOURCC_YVYU: u32 = fourcc_code(b'Y', b'V', b'Y', b'U');
+pub const DRM_FOURCC_UYVY: u32 = fourcc_code(b'U', b'Y', b'V', b'Y');
+pub const DRM_FOURCC_VYUY: u32 = fourcc_code(b'V', b'Y', b'U', b'Y');
+pub const DRM_FOURCC_AYUV: u32 = fourcc_code(b'A', b'Y', b'U', b'V');
+
+pub const DRM_FOURCC_NV12: u32 = fourcc_code(b'N', b'V', b'1', b'2');
+pub const DRM_FOURCC_NV21: u32 = fourcc_code(b'N', b'V', b'2', b'1');
+pub const DRM_FOURCC_NV16: u32 = fourcc_code(b'N', b'V', b'1', b'6');
+pub const DRM_FOURCC_NV61: u32 = fourcc_code(b'N', b'V', b'6', b'1');
+pub const DRM_FOURCC_NV24: u32 = fourcc_code(b'N', b'V', b'2', b'4');
+pub const DRM_FOURCC_NV42: u32 = fourcc_code(b'N', b'V', b'4', b'2');
+
+pub const DRM_FOURCC_YUV420: u32 = fourcc_code(b'Y', b'U', b'1', b'2');
+pub const DRM_FOURCC_YVU420: u32 = fourcc_code(b'Y', b'V', b'1', b'2');
+pub const DRM_FOURCC_YUV422: u32 = fourcc_code(b'Y', b'U', b'1', b'6');
+pub const DRM_FOURCC_YVU422: u32 = fourcc_code(b'Y', b'V', b'1', b'6');
+pub const DRM_FOURCC_YUV444: u32 = fourcc_code(b'Y', b'U', b'2', b'4');
+pub const DRM_FOURCC_YVU444: u32 = fourcc_code(b'Y', b'V', b'2', b'4');
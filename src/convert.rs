@@ -0,0 +1,76 @@
+//! Format-conversion capability queries, ported from the kernel's
+//! `drm_format_helper` conversion table. These answer "can a buffer in
+//! format A be blitted into format B without a color-space change", which
+//! is what compositors and software renderers need before picking an
+//! output format.
+
+use crate::DrmFormat;
+
+impl DrmFormat {
+    /// Whether a buffer in this format can be converted into `dst`, e.g. by
+    /// a `drm_format_helper`-style blit.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFormat;
+    /// assert!(DrmFormat::Xrgb8888.can_convert_to(DrmFormat::Rgb565));
+    /// assert!(!DrmFormat::Rgb565.can_convert_to(DrmFormat::Rgb888));
+    /// ```
+    pub fn can_convert_to(&self, dst: DrmFormat) -> bool {
+        Self::conversion_table(*self).contains(&dst)
+    }
+
+    /// All formats this format can be converted to. Useful for filtering an
+    /// advertised format list down to the ones a fixed source buffer format
+    /// can actually emit, mirroring `drm_fb_build_fourcc_list`.
+    pub fn conversion_targets(&self) -> impl Iterator<Item = DrmFormat> {
+        Self::conversion_table(*self).iter().copied()
+    }
+
+    fn conversion_table(format: DrmFormat) -> &'static [DrmFormat] {
+        use DrmFormat::*;
+
+        match format {
+            Xrgb8888 | Argb8888 => &[
+                Xrgb8888,
+                Argb8888,
+                Xrgb2101010,
+                Argb2101010,
+                Rgb565,
+                Rgb888,
+            ],
+            Rgb565 | Rgb888 => &[Xrgb8888, Argb8888],
+            Xrgb2101010 | Argb2101010 => &[Xrgb2101010, Argb2101010],
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn packed_rgb_converts_to_tiered_targets() {
+        assert!(DrmFormat::Xrgb8888.can_convert_to(DrmFormat::Argb8888));
+        assert!(DrmFormat::Xrgb8888.can_convert_to(DrmFormat::Rgb565));
+        assert!(!DrmFormat::Xrgb8888.can_convert_to(DrmFormat::Nv12));
+    }
+
+    #[test]
+    fn narrower_formats_only_upconvert() {
+        assert!(DrmFormat::Rgb565.can_convert_to(DrmFormat::Xrgb8888));
+        assert!(!DrmFormat::Rgb565.can_convert_to(DrmFormat::Rgb888));
+    }
+
+    #[test]
+    fn conversion_targets_lists_every_destination() {
+        let targets: Vec<_> = DrmFormat::Rgb888.conversion_targets().collect();
+        assert_eq!(targets, vec![DrmFormat::Xrgb8888, DrmFormat::Argb8888]);
+    }
+
+    #[test]
+    fn unmapped_format_has_no_targets() {
+        assert_eq!(DrmFormat::Nv12.conversion_targets().count(), 0);
+    }
+}
@@ -0,0 +1,153 @@
+//! Per-format pixel layout metadata: plane count, block size, and chroma
+//! subsampling.
+//!
+//! The plane count, bits-per-pixel and subsampling queries match
+//! exhaustively over [`DrmFormat`](crate::as_enum::DrmFormat) rather than
+//! falling back on a wildcard arm, so adding a new format to
+//! `as_enum`/`consts` forces an explicit, reviewed decision about its
+//! layout instead of silently inheriting a default.
+//! [`DrmFormat::block_dimensions`] returns `(1, 1)` for every format
+//! without matching on it.
+
+use crate::DrmFormat;
+
+impl DrmFormat {
+    /// The number of planes a buffer in this format is split across.
+    ///
+    /// Packed formats (e.g. [`DrmFormat::Xrgb8888`]) have a single plane;
+    /// planar YUV formats may have two or three.
+    pub fn num_planes(&self) -> u8 {
+        use DrmFormat::*;
+
+        match self {
+            Rgb332 | Bgr233 | Xrgb1555 | Xbgr1555 | Rgbx5551 | Bgrx5551 | Argb1555 | Abgr1555
+            | Rgba5551 | Bgra5551 | Rgb565 | Bgr565 | Rgb888 | Bgr888 | Xrgb8888 | Xbgr8888
+            | Rgbx8888 | Bgrx8888 | Argb8888 | Abgr8888 | Rgba8888 | Bgra8888 | Xrgb2101010
+            | Xbgr2101010 | Rgbx1010102 | Bgrx1010102 | Argb2101010 | Abgr2101010
+            | Rgba1010102 | Bgra1010102 | Yuyv | Yvyu | Uyvy | Vyuy | Ayuv => 1,
+            Nv12 | Nv21 | Nv16 | Nv61 | Nv24 | Nv42 => 2,
+            Yuv420 | Yvu420 | Yuv422 | Yvu422 | Yuv444 | Yvu444 => 3,
+        }
+    }
+
+    /// The number of bits each pixel occupies, for single-plane packed
+    /// formats. Returns `None` for planar formats, whose planes have
+    /// differing layouts.
+    pub fn bits_per_pixel(&self) -> Option<u32> {
+        use DrmFormat::*;
+
+        match self {
+            Rgb332 | Bgr233 => Some(8),
+            Xrgb1555 | Xbgr1555 | Rgbx5551 | Bgrx5551 | Argb1555 | Abgr1555 | Rgba5551
+            | Bgra5551 | Rgb565 | Bgr565 | Yuyv | Yvyu | Uyvy | Vyuy => Some(16),
+            Rgb888 | Bgr888 => Some(24),
+            Xrgb8888 | Xbgr8888 | Rgbx8888 | Bgrx8888 | Argb8888 | Abgr8888 | Rgba8888
+            | Bgra8888 | Xrgb2101010 | Xbgr2101010 | Rgbx1010102 | Bgrx1010102
+            | Argb2101010 | Abgr2101010 | Rgba1010102 | Bgra1010102 | Ayuv => Some(32),
+            Nv12 | Nv21 | Nv16 | Nv61 | Nv24 | Nv42 | Yuv420 | Yvu420 | Yuv422 | Yvu422
+            | Yuv444 | Yvu444 => None,
+        }
+    }
+
+    /// The width and height, in pixels, of a single compressed block. `(1,
+    /// 1)` for every format currently recognized, since none of them are
+    /// block-compressed yet.
+    pub fn block_dimensions(&self) -> (u32, u32) {
+        (1, 1)
+    }
+
+    /// The number of bytes a single block (or, for non-block-compressed
+    /// formats, a single pixel) occupies. `None` for planar formats, whose
+    /// planes have differing layouts.
+    pub fn bytes_per_block(&self) -> Option<u32> {
+        self.bits_per_pixel().map(|bpp| bpp / 8)
+    }
+
+    /// The horizontal chroma subsampling factor: how many luma samples
+    /// correspond to one chroma sample along the x axis. `1` for
+    /// non-subsampled formats.
+    pub fn hsub(&self) -> u32 {
+        use DrmFormat::*;
+
+        match self {
+            Yuyv | Yvyu | Uyvy | Vyuy | Nv12 | Nv21 | Nv16 | Nv61 | Yuv420 | Yvu420
+            | Yuv422 | Yvu422 => 2,
+            Rgb332 | Bgr233 | Xrgb1555 | Xbgr1555 | Rgbx5551 | Bgrx5551 | Argb1555
+            | Abgr1555 | Rgba5551 | Bgra5551 | Rgb565 | Bgr565 | Rgb888 | Bgr888
+            | Xrgb8888 | Xbgr8888 | Rgbx8888 | Bgrx8888 | Argb8888 | Abgr8888 | Rgba8888
+            | Bgra8888 | Xrgb2101010 | Xbgr2101010 | Rgbx1010102 | Bgrx1010102
+            | Argb2101010 | Abgr2101010 | Rgba1010102 | Bgra1010102 | Ayuv | Nv24 | Nv42
+            | Yuv444 | Yvu444 => 1,
+        }
+    }
+
+    /// The vertical chroma subsampling factor: how many luma samples
+    /// correspond to one chroma sample along the y axis. `1` for
+    /// non-subsampled formats.
+    pub fn vsub(&self) -> u32 {
+        use DrmFormat::*;
+
+        match self {
+            Nv12 | Nv21 | Yuv420 | Yvu420 => 2,
+            Rgb332 | Bgr233 | Xrgb1555 | Xbgr1555 | Rgbx5551 | Bgrx5551 | Argb1555
+            | Abgr1555 | Rgba5551 | Bgra5551 | Rgb565 | Bgr565 | Rgb888 | Bgr888
+            | Xrgb8888 | Xbgr8888 | Rgbx8888 | Bgrx8888 | Argb8888 | Abgr8888 | Rgba8888
+            | Bgra8888 | Xrgb2101010 | Xbgr2101010 | Rgbx1010102 | Bgrx1010102
+            | Argb2101010 | Abgr2101010 | Rgba1010102 | Bgra1010102 | Yuyv | Yvyu | Uyvy
+            | Vyuy | Ayuv | Nv16 | Nv61 | Nv24 | Nv42 | Yuv422 | Yvu422 | Yuv444
+            | Yvu444 => 1,
+        }
+    }
+
+    /// The minimum stride, in bytes, of a row of pixels `width` wide, for
+    /// single-plane packed formats. Returns `None` for planar formats,
+    /// which should compute a stride per-plane using [`Self::hsub`] and
+    /// [`Self::vsub`] instead.
+    pub fn min_bytes_per_row(&self, width: u32) -> Option<u32> {
+        let bpp = self.bits_per_pixel()?;
+        Some((width * bpp).div_ceil(8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packed_format_has_bits_per_pixel() {
+        assert_eq!(DrmFormat::Xrgb8888.bits_per_pixel(), Some(32));
+        assert_eq!(DrmFormat::Rgb565.bits_per_pixel(), Some(16));
+    }
+
+    #[test]
+    fn planar_format_has_no_bits_per_pixel() {
+        assert_eq!(DrmFormat::Nv12.bits_per_pixel(), None);
+    }
+
+    #[test]
+    fn planar_formats_report_plane_count() {
+        assert_eq!(DrmFormat::Xrgb8888.num_planes(), 1);
+        assert_eq!(DrmFormat::Nv12.num_planes(), 2);
+        assert_eq!(DrmFormat::Yuv420.num_planes(), 3);
+    }
+
+    #[test]
+    fn planar_yuv_formats_are_subsampled() {
+        assert_eq!(DrmFormat::Nv12.hsub(), 2);
+        assert_eq!(DrmFormat::Nv12.vsub(), 2);
+        assert_eq!(DrmFormat::Xrgb8888.hsub(), 1);
+        assert_eq!(DrmFormat::Xrgb8888.vsub(), 1);
+    }
+
+    #[test]
+    fn packed_yuv_is_only_horizontally_subsampled() {
+        assert_eq!(DrmFormat::Yuyv.hsub(), 2);
+        assert_eq!(DrmFormat::Yuyv.vsub(), 1);
+    }
+
+    #[test]
+    fn min_bytes_per_row_scales_with_width() {
+        assert_eq!(DrmFormat::Rgb888.min_bytes_per_row(1), Some(3));
+        assert_eq!(DrmFormat::Rgb565.min_bytes_per_row(3), Some(6));
+    }
+}
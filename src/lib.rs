@@ -1,4 +1,4 @@
-#![feature(try_trait)]
+#![no_std]
 #![allow(non_camel_case_types)]
 
 //! [`DrmFormat`] is an enum representing every pixel format supported by DRM
@@ -28,22 +28,84 @@
 //! [drm_wiki]: https://en.wikipedia.org/wiki/Direct_Rendering_Managerz
 //! [canonical]: https://github.com/torvalds/linux/blame/master/include/uapi/drm/drm_fourcc.h
 
-use std::convert::TryFrom;
-use std::error::Error;
-use std::fmt;
-use std::fmt::{Debug, Display, Formatter};
+extern crate alloc;
+
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
 
 pub use as_enum::DrmFormat;
-use std::option::NoneError;
+
+pub use modifier::{
+    fourcc_mod_code, DrmFourccModifier, DrmModifier, DrmVendor, UnrecognizedVendor,
+    DRM_FORMAT_MOD_INVALID, DRM_FORMAT_MOD_LINEAR,
+};
 
 mod as_enum;
 mod consts;
+mod convert;
+mod layout;
+mod modifier;
+
+/// DRM's big-endian flag: OR'd into a raw fourcc value to indicate that the
+/// format's pixels are stored in big-endian byte order rather than the
+/// usual little-endian order.
+pub const DRM_FORMAT_BIG_ENDIAN: u32 = 1 << 31;
 
 impl DrmFormat {
     /// Get the string representation of the format's fourcc.
     pub fn string_form(&self) -> String {
         fourcc_string_form(*self as u32).expect("Must be valid fourcc")
     }
+
+    /// The raw fourcc value, usable in const contexts.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFormat;
+    /// const XRGB8888: u32 = DrmFormat::Xrgb8888.raw();
+    /// assert_eq!(XRGB8888, 875713112);
+    /// ```
+    pub const fn raw(self) -> u32 {
+        self as u32
+    }
+
+    /// Whether `value` has the [`DRM_FORMAT_BIG_ENDIAN`] bit set.
+    pub const fn is_big_endian(value: u32) -> bool {
+        value & DRM_FORMAT_BIG_ENDIAN != 0
+    }
+
+    /// Clears the [`DRM_FORMAT_BIG_ENDIAN`] bit, returning the base fourcc
+    /// value.
+    pub const fn strip_big_endian(value: u32) -> u32 {
+        value & !DRM_FORMAT_BIG_ENDIAN
+    }
+
+    /// Sets the [`DRM_FORMAT_BIG_ENDIAN`] bit on a raw fourcc value.
+    pub const fn with_big_endian(value: u32) -> u32 {
+        value | DRM_FORMAT_BIG_ENDIAN
+    }
+
+    /// Like [`TryFrom<u32>`](TryFrom), but also accepts values with the
+    /// [`DRM_FORMAT_BIG_ENDIAN`] bit set: the bit is stripped before
+    /// looking up the format, and whether it was present is returned
+    /// alongside the match.
+    ///
+    /// ```
+    /// # use drm_fourcc::DrmFormat;
+    /// let (format, big_endian) =
+    ///     DrmFormat::try_from_raw(DrmFormat::with_big_endian(875713112)).unwrap();
+    /// assert_eq!(format, DrmFormat::Xrgb8888);
+    /// assert!(big_endian);
+    /// ```
+    pub fn try_from_raw(value: u32) -> Result<(Self, bool), UnrecognizedFourcc> {
+        let big_endian = Self::is_big_endian(value);
+        let base = Self::strip_big_endian(value);
+
+        Self::from_u32(base)
+            .map(|format| (format, big_endian))
+            .ok_or(UnrecognizedFourcc(value))
+    }
 }
 
 impl Debug for DrmFormat {
@@ -100,6 +162,20 @@ impl UnrecognizedFourcc {
     pub fn string_form(&self) -> Option<String> {
         fourcc_string_form(self.0)
     }
+
+    /// Like [`Self::string_form`], but reads the fourcc in big-endian
+    /// (left-to-right) byte order instead of the usual little-endian order.
+    ///
+    /// ```
+    /// # use drm_fourcc::UnrecognizedFourcc;
+    /// assert_eq!(
+    ///     UnrecognizedFourcc(0x61766331).string_form_be(),
+    ///     Some("avc1".to_string())
+    /// );
+    /// ```
+    pub fn string_form_be(&self) -> Option<String> {
+        fourcc_string_form_from_bytes(self.0.to_be_bytes())
+    }
 }
 
 impl Debug for UnrecognizedFourcc {
@@ -120,31 +196,35 @@ impl Display for UnrecognizedFourcc {
     }
 }
 
-impl Error for UnrecognizedFourcc {}
+impl core::error::Error for UnrecognizedFourcc {}
 
 fn fourcc_string_form(fourcc: u32) -> Option<String> {
-    let string = String::from_utf8(fourcc.to_le_bytes().to_vec()).map_err(|_| NoneError)?;
+    fourcc_string_form_from_bytes(fourcc.to_le_bytes())
+}
 
+fn fourcc_string_form_from_bytes(bytes: [u8; 4]) -> Option<String> {
     let mut out = String::new();
 
-    let chars: Vec<char> = string.chars().collect();
-    let (start, last_chars) = chars.split_at(3);
-    let last = last_chars[0];
-
-    // first three bytes must be characters
-    for char in start {
-        if char.is_ascii_alphanumeric() {
-            out.push(*char);
+    // first three bytes must be ascii alphanumeric; work on the raw bytes
+    // directly rather than decoding as UTF-8, since a byte with the high
+    // bit set can combine with its neighbor into a single multi-byte
+    // `char`, leaving fewer than 4 chars to split at index 3
+    for byte in &bytes[..3] {
+        if byte.is_ascii_alphanumeric() {
+            out.push(*byte as char);
         } else {
             return None;
         }
     }
 
     // last byte is allowed to be null
-    if last == '\0' {
+    let last = bytes[3];
+    if last == 0 {
         out.push(' ');
+    } else if last.is_ascii_alphanumeric() {
+        out.push(last as char);
     } else {
-        out.push(last);
+        return None;
     }
 
     Some(out)
@@ -153,6 +233,7 @@ fn fourcc_string_form(fourcc: u32) -> Option<String> {
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use alloc::format;
 
     #[test]
     fn a_specific_var_has_correct_value() {
@@ -161,10 +242,7 @@ pub mod tests {
 
     #[test]
     fn enum_member_casts_to_const() {
-        assert_eq!(
-            DrmFormat::Xrgb8888 as u32,
-            consts::DRM_FOURCC_XRGB8888 as u32
-        );
+        assert_eq!(DrmFormat::Xrgb8888 as u32, consts::DRM_FOURCC_XRGB8888);
     }
 
     #[test]
@@ -179,6 +257,16 @@ pub mod tests {
         assert_eq!(fourcc_string_form(0x316376).unwrap(), "vc1 ");
     }
 
+    #[test]
+    fn fourcc_string_form_handles_non_ascii_bytes_without_panicking() {
+        // 0xa9c24241 is "AB" followed by the bytes 0xc2, 0xa9, which decode
+        // as a single multi-byte UTF-8 char if naively run through
+        // `str::chars`; make sure this rejects cleanly instead of panicking
+        // on the byte/char length mismatch.
+        assert_eq!(fourcc_string_form(0xa9c24241), None);
+        assert_eq!(UnrecognizedFourcc(0xa9c24241).string_form(), None);
+    }
+
     #[test]
     fn unrecognized_handles_valid_fourcc() {
         assert_eq!(
@@ -201,4 +289,27 @@ pub mod tests {
         let b = a;
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn try_from_raw_accepts_big_endian_tagged_value() {
+        let (format, big_endian) =
+            DrmFormat::try_from_raw(DrmFormat::with_big_endian(875713112)).unwrap();
+        assert_eq!(format, DrmFormat::Xrgb8888);
+        assert!(big_endian);
+    }
+
+    #[test]
+    fn try_from_raw_reports_no_big_endian_flag() {
+        let (format, big_endian) = DrmFormat::try_from_raw(875713112).unwrap();
+        assert_eq!(format, DrmFormat::Xrgb8888);
+        assert!(!big_endian);
+    }
+
+    #[test]
+    fn string_form_be_reads_left_to_right() {
+        assert_eq!(
+            UnrecognizedFourcc(0x61766331).string_form_be(),
+            Some("avc1".into())
+        );
+    }
 }
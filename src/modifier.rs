@@ -0,0 +1,175 @@
+//! Types for DRM format modifiers, which describe a buffer's memory layout
+//! (tiling, compression, etc.) in addition to its fourcc.
+//!
+//! A modifier is a `u64` where the top 8 bits identify a vendor namespace
+//! and the low 56 bits are a vendor-private code, built with
+//! [`fourcc_mod_code`].
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+
+use crate::DrmFormat;
+
+/// The vendor namespace a [`DrmModifier`] belongs to.
+///
+/// This is the top 8 bits of the modifier, which determine how the
+/// remaining 56 bits should be interpreted.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+#[repr(u8)]
+pub enum DrmVendor {
+    None = 0,
+    Intel = 1,
+    Amd = 2,
+    Nvidia = 3,
+    Samsung = 4,
+    Qcom = 5,
+    Vivante = 6,
+    Broadcom = 7,
+    Arm = 8,
+    Allwinner = 9,
+    Amlogic = 10,
+}
+
+impl TryFrom<u8> for DrmVendor {
+    type Error = UnrecognizedVendor;
+
+    /// Convert from the vendor byte (the top 8 bits of a modifier).
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => DrmVendor::None,
+            1 => DrmVendor::Intel,
+            2 => DrmVendor::Amd,
+            3 => DrmVendor::Nvidia,
+            4 => DrmVendor::Samsung,
+            5 => DrmVendor::Qcom,
+            6 => DrmVendor::Vivante,
+            7 => DrmVendor::Broadcom,
+            8 => DrmVendor::Arm,
+            9 => DrmVendor::Allwinner,
+            10 => DrmVendor::Amlogic,
+            _ => return Err(UnrecognizedVendor(value)),
+        })
+    }
+}
+
+/// Build a modifier from a vendor and a vendor-private 56-bit code.
+///
+/// ```
+/// # use drm_fourcc::{fourcc_mod_code, DrmVendor};
+/// assert_eq!(fourcc_mod_code(DrmVendor::None, 0), 0);
+/// ```
+pub const fn fourcc_mod_code(vendor: DrmVendor, val: u64) -> u64 {
+    ((vendor as u64) << 56) | (val & 0x00ff_ffff_ffff_ffff)
+}
+
+/// No modifier: the buffer is laid out in the default, linear fashion.
+pub const DRM_FORMAT_MOD_LINEAR: u64 = fourcc_mod_code(DrmVendor::None, 0);
+
+/// An invalid modifier, used by some APIs to indicate that the modifier is
+/// unknown or unspecified.
+pub const DRM_FORMAT_MOD_INVALID: u64 = fourcc_mod_code(DrmVendor::None, 0x00ff_ffff_ffff_ffff);
+
+/// A DRM format modifier, describing a buffer's tiling or compression
+/// layout alongside its [`DrmFormat`].
+///
+/// ```
+/// # use std::convert::TryFrom;
+/// # use drm_fourcc::{DrmModifier, DrmVendor, DRM_FORMAT_MOD_LINEAR};
+/// let modifier = DrmModifier::try_from(DRM_FORMAT_MOD_LINEAR).unwrap();
+/// assert_eq!(modifier.vendor(), Some(DrmVendor::None));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DrmModifier(pub u64);
+
+impl DrmModifier {
+    /// The vendor namespace this modifier belongs to, or `None` if the
+    /// vendor byte isn't one we recognize.
+    pub fn vendor(&self) -> Option<DrmVendor> {
+        DrmVendor::try_from((self.0 >> 56) as u8).ok()
+    }
+}
+
+impl TryFrom<u64> for DrmModifier {
+    type Error = UnrecognizedVendor;
+
+    /// Convert from a raw modifier value, failing if the vendor byte isn't
+    /// recognized.
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        DrmVendor::try_from((value >> 56) as u8)?;
+        Ok(DrmModifier(value))
+    }
+}
+
+impl Debug for DrmModifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_tuple("DrmModifier");
+
+        if let Some(vendor) = self.vendor() {
+            debug.field(&vendor);
+        }
+
+        debug.field(&self.0).finish()
+    }
+}
+
+impl Display for DrmModifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Wraps a modifier's vendor byte (its top 8 bits) that isn't one we
+/// recognize.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct UnrecognizedVendor(pub u8);
+
+impl Debug for UnrecognizedVendor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("UnrecognizedVendor").field(&self.0).finish()
+    }
+}
+
+impl Display for UnrecognizedVendor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl core::error::Error for UnrecognizedVendor {}
+
+/// A fourcc and modifier pair, as used by APIs like
+/// `EGL_EXT_image_dma_buf_import_modifiers` and GBM that need both to fully
+/// describe a buffer.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DrmFourccModifier {
+    pub fourcc: DrmFormat,
+    pub modifier: DrmModifier,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fourcc_mod_code_packs_vendor_and_value() {
+        assert_eq!(fourcc_mod_code(DrmVendor::Intel, 5), (1u64 << 56) | 5);
+    }
+
+    #[test]
+    fn modifier_vendor_extracts_top_byte() {
+        let modifier = DrmModifier::try_from(fourcc_mod_code(DrmVendor::Amd, 0)).unwrap();
+        assert_eq!(modifier.vendor(), Some(DrmVendor::Amd));
+    }
+
+    #[test]
+    fn modifier_rejects_unrecognized_vendor() {
+        assert!(DrmModifier::try_from(0xffu64 << 56).is_err());
+    }
+
+    #[test]
+    fn linear_modifier_has_none_vendor() {
+        let modifier = DrmModifier::try_from(DRM_FORMAT_MOD_LINEAR).unwrap();
+        assert_eq!(modifier.vendor(), Some(DrmVendor::None));
+    }
+}